@@ -1,10 +1,12 @@
 use std::{cmp, time::Duration};
 
 use crossbeam_channel::{Receiver, Sender};
+use crossterm::event::KeyCode;
 use tui::{
     backend::Backend,
     layout::{Constraint, Layout},
-    text::Text,
+    style::{Color, Modifier, Style},
+    text::{Span, Spans, Text},
     widgets::{Block, BorderType, Borders, Paragraph},
     Frame,
 };
@@ -12,15 +14,23 @@ use tui::{
 #[cfg(not(feature = "no-copy"))]
 use clipboard::{ClipboardContext, ClipboardProvider};
 
-#[cfg(not(feature = "no-copy"))]
-use crate::crates_io::CrateSearch;
-
 use crate::{
-    crates_io::{CrateSearchResponse, CrateSearcher, CratesSort},
+    bookmarks::BookmarkStore,
+    crates_io::{CrateSearch, CrateSearchResponse, CrateSearcher, CratesSort},
+    detail::Pager,
+    filter, fuzzy,
     input::InputEvent,
+    keymap::{Action, Keymap},
+    textarea::TextArea,
+    url,
     widgets::{CrateWidget, InputWidget, SortingWidget},
 };
 
+/// Number of rows scrolled by a single PageUp/PageDown in [`AppMode::Detail`].
+const DETAIL_PAGE_ROWS: usize = 10;
+/// Column width the detail pager reflows its text to.
+const DETAIL_WRAP_WIDTH: usize = 80;
+
 pub struct SortingField {
     pub(crate) selection: usize,
     pub(crate) items: Vec<CratesSort>,
@@ -49,8 +59,17 @@ impl From<&'_ CratesSort> for SortingField {
 
 pub enum AppMode {
     Normal,
-    Input(String, u64),
+    Input(TextArea, u64),
     Sorting(SortingField),
+    Detail(Pager),
+    /// The `/` search sub-mode within [`AppMode::Detail`], holding the
+    /// in-progress query and the pager it will search once confirmed.
+    DetailSearch(Pager, String),
+    /// Live glob/substring filtering of the already-fetched page of
+    /// results, entered with `/` from [`AppMode::Normal`].
+    Filter(TextArea),
+    /// Browsing saved bookmarks, holding the highlighted entry's index.
+    Bookmarks(usize),
 }
 
 pub struct App {
@@ -63,6 +82,11 @@ pub struct App {
     sort: CratesSort,
     mode: AppMode,
     selection: Option<usize>,
+    keymap: Keymap,
+    bookmarks: BookmarkStore,
+    /// The confirmed [`AppMode::Filter`] pattern, kept applied after
+    /// returning to [`AppMode::Normal`] via Enter.
+    filter: Option<String>,
 }
 
 impl App {
@@ -74,9 +98,12 @@ impl App {
             quit: false,
             inpt: Some("".to_string()),
             page: 1,
-            mode: AppMode::Input("".to_string(), 0),
+            mode: AppMode::Input(TextArea::new(), 0),
             sort: CratesSort::Relevance,
             selection: None,
+            keymap: Keymap::load(),
+            bookmarks: BookmarkStore::load(),
+            filter: None,
         }
     }
 
@@ -98,7 +125,7 @@ impl App {
         let area = splits[1];
         let message = match self.mode {
             AppMode::Normal => {
-                Text::raw("Press N/P to move between pages.  Press f to search for a term\nPress J/K to change the highlighted Crate and press C to copy it's Cargo.toml string") 
+                Text::raw("Press N/P to move between pages.  Press f to search for a term\nPress J/K to change the highlighted Crate, Enter to view it, C to copy it's Cargo.toml string, O to open its repository, D for its docs.rs page\nPress B to bookmark it, V to view your bookmarks")
             }
             AppMode::Input(_, _) => {
                 "Type to enter your search term.  Press Enter to confirm.  Press ESC to cancel".into()
@@ -106,6 +133,18 @@ impl App {
             AppMode::Sorting(_) => {
                 "Press J/K to move between options.  Press Enter to confirm.  Press ESC to cancel".into()
             }
+            AppMode::Detail(_) => {
+                "Press J/K or PageUp/PageDown to scroll.  Press / to search.  Press ESC to go back".into()
+            }
+            AppMode::DetailSearch(_, _) => {
+                "Type to search the crate's details.  Press Enter to jump to the next match.  Press ESC to cancel".into()
+            }
+            AppMode::Filter(_) => {
+                "Type to filter the displayed Crates by name/description.  Press Enter to confirm, ESC to clear".into()
+            }
+            AppMode::Bookmarks(_) => {
+                "Press J/K to move between bookmarks, C to copy, X to delete.  Press ESC to go back".into()
+            }
         };
         let message = Paragraph::new(message);
         f.render_widget(message, top);
@@ -123,12 +162,8 @@ impl App {
 
         if let Some(CrateSearchResponse { ref crates }) = self.crates {
             let mut widgets = Vec::new();
-            for (i, crte) in crates.iter().enumerate() {
-                if let Some(selection) = self.selection {
-                    widgets.push(CrateWidget::new(crte, selection == i));
-                } else {
-                    widgets.push(CrateWidget::new(crte, false));
-                }
+            for i in self.filtered_indices() {
+                widgets.push(CrateWidget::new(&crates[i], self.selection == Some(i)));
             }
 
             let splits = Layout::default()
@@ -158,7 +193,15 @@ impl App {
         match &self.mode {
             AppMode::Input(msg, ticks) => {
                 let show_cursor = (ticks & 1) == 0;
-                let inpt = InputWidget::new("Enter you search term", msg.as_str(), show_cursor);
+                let text = msg.as_string();
+                // `msg.cursor()` is threaded through so InputWidget can render the
+                // caret at its true position instead of always at the end.
+                let inpt = InputWidget::new(
+                    "Enter you search term",
+                    text.as_str(),
+                    msg.cursor(),
+                    show_cursor,
+                );
                 f.render_widget(inpt, f.size());
             }
             AppMode::Normal => {}
@@ -166,6 +209,81 @@ impl App {
                 let widget = SortingWidget::new(state, "Select you sorting method");
                 f.render_widget(widget, f.size());
             }
+            AppMode::Filter(editor) => {
+                let text = editor.as_string();
+                // Same cursor-aware InputWidget as AppMode::Input, always blinking
+                // on since there are no idle ticks to drive a blink cadence here.
+                let inpt = InputWidget::new(
+                    "Filter displayed Crates",
+                    text.as_str(),
+                    editor.cursor(),
+                    true,
+                );
+                f.render_widget(inpt, f.size());
+            }
+            AppMode::Bookmarks(selection) => {
+                let text = if self.bookmarks.bookmarks().is_empty() {
+                    "No bookmarks yet.  Press b on a Crate in Normal mode to save it".to_string()
+                } else {
+                    self.bookmarks
+                        .bookmarks()
+                        .iter()
+                        .enumerate()
+                        .map(|(i, bookmark)| {
+                            let marker = if i == *selection { "> " } else { "  " };
+                            format!("{}{} {}", marker, bookmark.name, bookmark.version)
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                };
+                let widget = Paragraph::new(text).block(
+                    Block::default()
+                        .title("Bookmarks")
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Thick),
+                );
+                f.render_widget(widget, f.size());
+            }
+            AppMode::Detail(pager) | AppMode::DetailSearch(pager, _) => {
+                let (row, col) = pager.cursor();
+                let lines: Vec<Spans> = pager
+                    .lines()
+                    .iter()
+                    .enumerate()
+                    .skip(row)
+                    .take(f.size().height as usize)
+                    .map(|(i, line)| {
+                        let mut ranges: Vec<(usize, usize, Style)> = pager
+                            .url_spans(i)
+                            .iter()
+                            .map(|span| {
+                                (
+                                    span.start,
+                                    span.end,
+                                    Style::default()
+                                        .fg(Color::Cyan)
+                                        .add_modifier(Modifier::UNDERLINED),
+                                )
+                            })
+                            .collect();
+                        ranges.extend(pager.matches_in_row(i).into_iter().map(|(start, end)| {
+                            let style = if i == row && start == col {
+                                Style::default().bg(Color::Yellow).fg(Color::Black)
+                            } else {
+                                Style::default().bg(Color::DarkGray)
+                            };
+                            (start, end, style)
+                        }));
+                        spans_with_highlights(line, ranges)
+                    })
+                    .collect();
+                let widget = Paragraph::new(lines).block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Thick),
+                );
+                f.render_widget(widget, f.size());
+            }
         }
     }
 
@@ -173,130 +291,276 @@ impl App {
         if let Ok(inpt) = self.input_rx.recv_timeout(Duration::from_secs(1)) {
             match &mut self.mode {
                 AppMode::Normal => match inpt {
-                    InputEvent::Char(c) => match c {
-                        'f' | 'F' => {
-                            self.mode = AppMode::Input("".to_string(), 0);
-                        }
-                        'q' | 'Q' => {
-                            self.quit = true;
-                        }
-                        'n' | 'N' => {
-                            if self.crates.as_ref().map(|c| c.crates.len()).unwrap_or(0) > 0 {
-                                self.page += 1;
-                                self.do_search();
-                            }
-                        }
-                        'p' | 'P' => {
-                            if self.page > 1 {
-                                self.page -= 1;
-                                self.do_search();
-                            }
+                    InputEvent::Key(key) => {
+                        if let Some(action) = self.keymap.resolve(key.code, key.modifiers) {
+                            self.dispatch_normal(action);
+                        } else if key.code == KeyCode::Enter {
+                            self.view_selection();
                         }
-                        'j' | 'J' => {
-                            if let Some(selection) = self.selection {
-                                self.selection = Some(cmp::min(
-                                    selection + 1,
-                                    self.crates
-                                        .as_ref()
-                                        .map(|resp| &resp.crates)
-                                        .map(|crates| crates.len() - 1)
-                                        .unwrap_or(0),
-                                ));
-                            }
-                        }
-                        'k' | 'K' => {
-                            if let Some(selection) = self.selection {
-                                if selection > 0 {
-                                    self.selection = Some(selection - 1);
-                                }
-                            }
+                    }
+                    InputEvent::Results(results) => self.set_results(results),
+                    InputEvent::Details(details) => {
+                        self.mode = AppMode::Detail(Pager::new(&details, DETAIL_WRAP_WIDTH));
+                    }
+                },
+                AppMode::Filter(editor) => match inpt {
+                    InputEvent::Key(key) => match key.code {
+                        KeyCode::Esc => {
+                            self.filter = None;
+                            self.mode = AppMode::Normal;
                         }
-                        's' | 'S' => {
-                            self.mode = AppMode::Sorting(SortingField::from(&self.sort));
+                        KeyCode::Enter => {
+                            self.filter = if editor.is_empty() {
+                                None
+                            } else {
+                                Some(editor.as_string())
+                            };
+                            self.mode = AppMode::Normal;
                         }
-                        'c' | 'C' => {
-                            self.copy_selection();
+                        _ => {
+                            editor.handle_key(key.code, key.modifiers);
                         }
-                        _ => {}
                     },
-                    InputEvent::Results(results) => {
-                        self.crates = Some(results);
-                        self.selection = if let Some(ref crates) = self.crates {
-                            if crates.crates.len() > 0 {
-                                Some(0)
-                            } else {
-                                None
-                            }
-                        } else {
-                            None
-                        }
-                    }
-                    _ => {}
+                    InputEvent::Results(results) => self.set_results(results),
+                    InputEvent::Details(_) => {}
                 },
-                AppMode::Input(ref mut msg, ref mut ticks) => match inpt {
-                    InputEvent::Esc => self.mode = AppMode::Normal,
-                    InputEvent::Enter => {
-                        let replaced = std::mem::take(msg);
-                        self.page = 1;
-                        self.inpt = Some(replaced);
-                        self.do_search();
-                        self.mode = AppMode::Normal;
-                    }
-                    InputEvent::Backspace => {
-                        let _ = msg.pop();
-                    }
-                    InputEvent::Char(c) => msg.push(c),
-                    InputEvent::Tick => {
-                        *ticks = ticks.wrapping_add(1);
-                    }
-                    InputEvent::Results(results) => {
-                        self.crates = Some(results);
-                        self.selection = if let Some(ref crates) = self.crates {
-                            if crates.crates.len() > 0 {
-                                Some(0)
-                            } else {
-                                None
+                AppMode::Input(ref mut editor, ref mut ticks) => match inpt {
+                    InputEvent::Key(key) => match key.code {
+                        KeyCode::Esc => self.mode = AppMode::Normal,
+                        KeyCode::Enter => {
+                            let replaced = editor.take();
+                            self.page = 1;
+                            self.inpt = Some(replaced);
+                            self.do_search();
+                            self.mode = AppMode::Normal;
+                        }
+                        _ => {
+                            if !editor.handle_key(key.code, key.modifiers) {
+                                *ticks = ticks.wrapping_add(1);
                             }
-                        } else {
-                            None
                         }
-                    }
+                    },
+                    InputEvent::Results(results) => self.set_results(results),
+                    InputEvent::Details(_) => {}
                 },
                 AppMode::Sorting(SortingField {
                     selection,
                     items,
                     strs: _,
                 }) => match inpt {
-                    InputEvent::Esc => self.mode = AppMode::Normal,
-                    InputEvent::Enter => {
-                        self.sort = items[*selection].clone();
-                        self.page = 1;
-                        self.mode = AppMode::Normal;
-                        self.do_search();
-                    }
-                    InputEvent::Char(c) => match c {
-                        'k' | 'K' => {
-                            *selection = selection.saturating_sub(1);
-                        }
-                        'j' | 'J' => {
-                            *selection = cmp::min(*selection + 1, 4);
+                    InputEvent::Key(key) => match key.code {
+                        KeyCode::Esc => self.mode = AppMode::Normal,
+                        KeyCode::Enter => {
+                            self.sort = items[*selection].clone();
+                            self.page = 1;
+                            self.mode = AppMode::Normal;
+                            self.do_search();
                         }
-                        _ => {}
+                        _ => match self.keymap.resolve(key.code, key.modifiers) {
+                            Some(Action::MoveUp) => *selection = selection.saturating_sub(1),
+                            Some(Action::MoveDown) => *selection = cmp::min(*selection + 1, 4),
+                            _ => {}
+                        },
                     },
-                    InputEvent::Results(results) => {
-                        self.crates = Some(results);
-                        self.selection = if let Some(ref crates) = self.crates {
-                            if crates.crates.len() > 0 {
-                                Some(0)
-                            } else {
-                                None
+                    InputEvent::Results(results) => self.set_results(results),
+                    InputEvent::Details(_) => {}
+                },
+                AppMode::Bookmarks(selection) => {
+                    if let InputEvent::Key(key) = inpt {
+                        match key.code {
+                            KeyCode::Esc => self.mode = AppMode::Normal,
+                            _ => match self.keymap.resolve(key.code, key.modifiers) {
+                                Some(Action::MoveDown) => {
+                                    let len = self.bookmarks.bookmarks().len();
+                                    if len > 0 {
+                                        *selection = cmp::min(*selection + 1, len - 1);
+                                    }
+                                }
+                                Some(Action::MoveUp) => *selection = selection.saturating_sub(1),
+                                Some(Action::Copy) => self.copy_bookmark(*selection),
+                                Some(Action::Delete) => {
+                                    self.bookmarks.remove(*selection);
+                                    let len = self.bookmarks.bookmarks().len();
+                                    if *selection >= len {
+                                        *selection = len.saturating_sub(1);
+                                    }
+                                }
+                                _ => {}
+                            },
+                        }
+                    }
+                }
+                AppMode::Detail(pager) => {
+                    if let InputEvent::Key(key) = inpt {
+                        match key.code {
+                            KeyCode::Esc => self.mode = AppMode::Normal,
+                            _ => match self.keymap.resolve(key.code, key.modifiers) {
+                                Some(Action::MoveDown) => pager.scroll_down(1),
+                                Some(Action::MoveUp) => pager.scroll_up(1),
+                                Some(Action::PageDown) => pager.scroll_down(DETAIL_PAGE_ROWS),
+                                Some(Action::PageUp) => pager.scroll_up(DETAIL_PAGE_ROWS),
+                                Some(Action::NextPage) => pager.next_match(true),
+                                Some(Action::PrevPage) => pager.next_match(false),
+                                Some(Action::OpenRepository) => {
+                                    if let Some(repo_url) = pager.repository_url() {
+                                        let _ = url::open_url(repo_url);
+                                    }
+                                }
+                                Some(Action::OpenDocs) => {
+                                    let _ = url::open_url(&pager.docs_url());
+                                }
+                                Some(Action::Search) => {
+                                    if let AppMode::Detail(pager) =
+                                        std::mem::replace(&mut self.mode, AppMode::Normal)
+                                    {
+                                        self.mode = AppMode::DetailSearch(pager, String::new());
+                                    }
+                                }
+                                _ => {}
+                            },
+                        }
+                    }
+                }
+                AppMode::DetailSearch(pager, query) => {
+                    if let InputEvent::Key(key) = inpt {
+                        match key.code {
+                            KeyCode::Esc => {
+                                pager.clear_search();
+                                if let AppMode::DetailSearch(pager, _) =
+                                    std::mem::replace(&mut self.mode, AppMode::Normal)
+                                {
+                                    self.mode = AppMode::Detail(pager);
+                                }
+                            }
+                            KeyCode::Enter => {
+                                pager.search(query);
+                                if let AppMode::DetailSearch(pager, _) =
+                                    std::mem::replace(&mut self.mode, AppMode::Normal)
+                                {
+                                    self.mode = AppMode::Detail(pager);
+                                }
+                            }
+                            KeyCode::Backspace => {
+                                let _ = query.pop();
                             }
-                        } else {
-                            None
+                            KeyCode::Char(c) => query.push(c),
+                            _ => {}
                         }
                     }
-                    _ => {}
-                },
+                }
+            }
+        }
+    }
+
+    /// Dispatches an [`Action`] resolved from the keymap while in
+    /// [`AppMode::Normal`].
+    fn dispatch_normal(&mut self, action: Action) {
+        match action {
+            Action::StartSearch => self.mode = AppMode::Input(TextArea::new(), 0),
+            Action::Quit => self.quit = true,
+            Action::NextPage => {
+                if self.crates.as_ref().map(|c| c.crates.len()).unwrap_or(0) > 0 {
+                    self.page += 1;
+                    self.do_search();
+                }
+            }
+            Action::PrevPage => {
+                if self.page > 1 {
+                    self.page -= 1;
+                    self.do_search();
+                }
+            }
+            Action::MoveDown => self.move_selection(1),
+            Action::MoveUp => self.move_selection(-1),
+            Action::OpenSort => {
+                self.mode = AppMode::Sorting(SortingField::from(&self.sort));
+            }
+            Action::Copy => self.copy_selection(),
+            Action::OpenRepository => {
+                if let Some(repo_url) = self
+                    .selected_crate()
+                    .and_then(|crte| crte.repository().or_else(|| crte.homepage()))
+                {
+                    let _ = url::open_url(repo_url);
+                }
+            }
+            Action::OpenDocs => {
+                if let Some(crte) = self.selected_crate() {
+                    let _ = url::open_url(&format!("https://docs.rs/{}", crte.name()));
+                }
+            }
+            Action::Search => self.mode = AppMode::Filter(TextArea::new()),
+            Action::ToggleBookmark => {
+                if let Some(crte) = self.selected_crate() {
+                    self.bookmarks
+                        .toggle(crte.name(), crte.version(), &crte.get_toml_str());
+                }
+            }
+            Action::OpenBookmarks => self.mode = AppMode::Bookmarks(0),
+            Action::PageUp | Action::PageDown | Action::Delete => {}
+        }
+    }
+
+    fn selected_crate(&self) -> Option<&CrateSearch> {
+        let selection = self.selection?;
+        self.crates.as_ref()?.crates.get(selection)
+    }
+
+    /// The active filter pattern, if any: the in-progress [`AppMode::Filter`]
+    /// text while still editing, otherwise the last confirmed one.
+    fn filter_pattern(&self) -> Option<String> {
+        match &self.mode {
+            AppMode::Filter(editor) if !editor.is_empty() => Some(editor.as_string()),
+            AppMode::Filter(_) => None,
+            _ => self.filter.clone(),
+        }
+    }
+
+    /// Indices into `self.crates`'s crates that pass the active filter, in
+    /// display order. With no filter active, this is every index.
+    fn filtered_indices(&self) -> Vec<usize> {
+        let Some(ref crates) = self.crates else {
+            return Vec::new();
+        };
+        let pattern = self.filter_pattern();
+        crates
+            .crates
+            .iter()
+            .enumerate()
+            .filter(|(_, crte)| {
+                pattern
+                    .as_deref()
+                    .map(|pattern| filter::crate_matches(pattern, crte.name(), crte.description()))
+                    .unwrap_or(true)
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Moves the highlighted selection by `delta` positions within the
+    /// currently filtered/displayed crates, clamping at either end.
+    fn move_selection(&mut self, delta: isize) {
+        let indices = self.filtered_indices();
+        if indices.is_empty() {
+            self.selection = None;
+            return;
+        }
+
+        let current_pos = self
+            .selection
+            .and_then(|selection| indices.iter().position(|&i| i == selection))
+            .unwrap_or(0);
+        let new_pos = (current_pos as isize + delta).clamp(0, indices.len() as isize - 1) as usize;
+        self.selection = Some(indices[new_pos]);
+    }
+
+    /// Requests the full detail record for the highlighted crate; the result
+    /// arrives asynchronously as [`InputEvent::Details`] and transitions into
+    /// [`AppMode::Detail`].
+    fn view_selection(&mut self) {
+        if let (Some(selection), Some(ref crates)) = (self.selection, &self.crates) {
+            if let Some(crte) = crates.crates.get(selection) {
+                self.client.fetch_details(crte.name());
             }
         }
     }
@@ -307,6 +571,31 @@ impl App {
             .search_sorted(search.unwrap(), self.page, &self.sort);
     }
 
+    /// Stores a freshly-fetched page of results, fuzzy re-ranking it against
+    /// the active search term (falling back to the server's own ordering when
+    /// the term is empty), and resets the highlighted selection to the top of
+    /// whatever passes the active filter.
+    ///
+    /// The fuzzy re-rank only applies under [`CratesSort::Relevance`] — any
+    /// other sort is an explicit choice from the Sorting menu, and re-ordering
+    /// by text-match score would silently discard it.
+    fn set_results(&mut self, mut results: CrateSearchResponse) {
+        if self.sort == CratesSort::Relevance {
+            if let Some(query) = self.inpt.as_deref().filter(|q| !q.is_empty()) {
+                results.crates.sort_by(|a, b| {
+                    let score_a = fuzzy::best_score(query, a.name(), a.description());
+                    let score_b = fuzzy::best_score(query, b.name(), b.description());
+                    score_b
+                        .partial_cmp(&score_a)
+                        .unwrap_or(cmp::Ordering::Equal)
+                });
+            }
+        }
+
+        self.crates = Some(results);
+        self.selection = self.filtered_indices().first().copied();
+    }
+
     #[cfg(not(feature = "no-copy"))]
     fn copy_selection(&self) {
         if let Some(selection) = self.selection {
@@ -322,4 +611,43 @@ impl App {
 
     #[cfg(feature = "no-copy")]
     fn copy_selection(&self) {}
+
+    #[cfg(not(feature = "no-copy"))]
+    fn copy_bookmark(&self, selection: usize) {
+        if let Some(bookmark) = self.bookmarks.bookmarks().get(selection) {
+            let mut clipboard: ClipboardContext = ClipboardProvider::new().unwrap();
+            clipboard.set_contents(bookmark.toml.clone()).unwrap();
+        }
+    }
+
+    #[cfg(feature = "no-copy")]
+    fn copy_bookmark(&self, _selection: usize) {}
+}
+
+/// Splits `line` into styled `Spans`, applying `ranges` (byte `(start, end,
+/// style)` triples) as highlighted segments over the otherwise plain text.
+/// Ranges are sorted by start; a range overlapping one already applied is
+/// skipped.
+fn spans_with_highlights(line: &str, mut ranges: Vec<(usize, usize, Style)>) -> Spans<'static> {
+    ranges.sort_by_key(|&(start, _, _)| start);
+
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    for (start, end, style) in ranges {
+        let start = start.min(line.len());
+        let end = end.min(line.len()).max(start);
+        if start < pos {
+            continue;
+        }
+        if pos < start {
+            spans.push(Span::raw(line[pos..start].to_string()));
+        }
+        spans.push(Span::styled(line[start..end].to_string(), style));
+        pos = end;
+    }
+    if pos < line.len() {
+        spans.push(Span::raw(line[pos..].to_string()));
+    }
+
+    Spans::from(spans)
 }
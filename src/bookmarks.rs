@@ -0,0 +1,83 @@
+//! Local favorites: crates the user has bookmarked, persisted to a JSON file
+//! under the user config dir so they survive across runs.
+
+use std::{fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// A single bookmarked crate: enough to list it and to copy its Cargo.toml
+/// line without re-fetching anything.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Bookmark {
+    pub name: String,
+    pub version: String,
+    pub toml: String,
+}
+
+pub struct BookmarkStore {
+    bookmarks: Vec<Bookmark>,
+}
+
+impl BookmarkStore {
+    /// Loads the store from `~/.config/cratuity/bookmarks.json`, falling back
+    /// to an empty store if the file is missing or malformed.
+    pub fn load() -> Self {
+        let bookmarks = bookmarks_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+
+        Self { bookmarks }
+    }
+
+    fn save(&self) {
+        let Some(path) = bookmarks_path() else {
+            return;
+        };
+        let Some(parent) = path.parent() else {
+            return;
+        };
+        if fs::create_dir_all(parent).is_err() {
+            return;
+        }
+
+        if let Ok(contents) = serde_json::to_string_pretty(&self.bookmarks) {
+            let _ = fs::write(path, contents);
+        }
+    }
+
+    pub fn bookmarks(&self) -> &[Bookmark] {
+        &self.bookmarks
+    }
+
+    pub fn is_bookmarked(&self, name: &str) -> bool {
+        self.bookmarks.iter().any(|b| b.name == name)
+    }
+
+    /// Toggles `name`'s bookmark, adding it with `version`/`toml` if it's not
+    /// already saved, removing it otherwise. Persists the change either way.
+    pub fn toggle(&mut self, name: &str, version: &str, toml: &str) {
+        if let Some(pos) = self.bookmarks.iter().position(|b| b.name == name) {
+            self.bookmarks.remove(pos);
+        } else {
+            self.bookmarks.push(Bookmark {
+                name: name.to_string(),
+                version: version.to_string(),
+                toml: toml.to_string(),
+            });
+        }
+        self.save();
+    }
+
+    /// Removes the bookmark at `index`, if any, and persists the change.
+    pub fn remove(&mut self, index: usize) {
+        if index < self.bookmarks.len() {
+            self.bookmarks.remove(index);
+            self.save();
+        }
+    }
+}
+
+fn bookmarks_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("cratuity").join("bookmarks.json"))
+}
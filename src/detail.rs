@@ -0,0 +1,227 @@
+//! Full-screen pager for reading a crate's full detail record (description,
+//! keywords, repository, version history, ...), with an in-view `/` search.
+
+use std::cmp;
+
+use crate::{
+    crates_io::CrateDetails,
+    url::{self, UrlSpan},
+};
+
+/// A crate's detail record reflowed into a line-wrapped text buffer, plus the
+/// pager's scroll position and optional in-view search state.
+pub struct Pager {
+    name: String,
+    repository: Option<String>,
+    homepage: Option<String>,
+    lines: Vec<String>,
+    /// URL spans detected within each line of `lines`, by line index.
+    url_spans: Vec<Vec<UrlSpan>>,
+    row: usize,
+    col: usize,
+    search: Option<PagerSearch>,
+}
+
+/// An active `/` search within a [`Pager`]: the literal pattern being matched
+/// against every line, every match's `(row, col)`, and which one is current.
+struct PagerSearch {
+    pattern: String,
+    matches: Vec<(usize, usize)>,
+    current: usize,
+}
+
+impl Pager {
+    pub fn new(details: &CrateDetails, width: usize) -> Self {
+        let lines = reflow(&render_detail_text(details), width.max(1));
+        let url_spans = lines.iter().map(|line| url::locate_urls(line)).collect();
+
+        Self {
+            name: details.name().to_string(),
+            repository: details.repository().map(str::to_string),
+            homepage: details.homepage().map(str::to_string),
+            lines,
+            url_spans,
+            row: 0,
+            col: 0,
+            search: None,
+        }
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// URL spans detected within the line at `row`, for highlighting.
+    pub fn url_spans(&self, row: usize) -> &[UrlSpan] {
+        self.url_spans.get(row).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// The repository URL, falling back to the homepage, to open for the `o`
+    /// action.
+    pub fn repository_url(&self) -> Option<&str> {
+        self.repository.as_deref().or(self.homepage.as_deref())
+    }
+
+    /// The crate's docs.rs URL, for the `d` action.
+    pub fn docs_url(&self) -> String {
+        format!("https://docs.rs/{}", self.name)
+    }
+
+    pub fn cursor(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+
+    pub fn scroll_down(&mut self, by: usize) {
+        self.row = cmp::min(self.row + by, self.lines.len().saturating_sub(1));
+    }
+
+    pub fn scroll_up(&mut self, by: usize) {
+        self.row = self.row.saturating_sub(by);
+    }
+
+    /// Begins a `/` search, compiling `pattern` against the pager's lines
+    /// (case-insensitive substring match) and jumping to the first match at
+    /// or after the current cursor.
+    pub fn search(&mut self, pattern: &str) {
+        let needle = pattern.to_ascii_lowercase();
+        let mut matches = Vec::new();
+        for (row, line) in self.lines.iter().enumerate() {
+            let haystack = line.to_ascii_lowercase();
+            let mut start = 0;
+            while let Some(offset) = haystack[start..].find(&needle) {
+                let col = start + offset;
+                matches.push((row, col));
+                start = col + needle.len().max(1);
+                if start >= haystack.len() {
+                    break;
+                }
+            }
+        }
+
+        let current = matches
+            .iter()
+            .position(|&(row, col)| (row, col) >= (self.row, self.col))
+            .unwrap_or(0);
+
+        if let Some(&(row, col)) = matches.get(current) {
+            self.row = row;
+            self.col = col;
+        }
+
+        self.search = Some(PagerSearch {
+            pattern: needle,
+            matches,
+            current,
+        });
+    }
+
+    /// Jumps to the next (`forward = true`) or previous match of the active
+    /// search, wrapping around. No-op if no search is active or it found
+    /// nothing.
+    pub fn next_match(&mut self, forward: bool) {
+        if let Some(search) = &mut self.search {
+            if search.matches.is_empty() {
+                return;
+            }
+            search.current = if forward {
+                (search.current + 1) % search.matches.len()
+            } else {
+                (search.current + search.matches.len() - 1) % search.matches.len()
+            };
+            let (row, col) = search.matches[search.current];
+            self.row = row;
+            self.col = col;
+        }
+    }
+
+    pub fn matches(&self) -> &[(usize, usize)] {
+        self.search
+            .as_ref()
+            .map(|s| s.matches.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub fn search_pattern(&self) -> Option<&str> {
+        self.search.as_ref().map(|s| s.pattern.as_str())
+    }
+
+    /// Byte `(start, end)` ranges of every match of the active search that
+    /// falls on `row`, for highlighting.
+    pub fn matches_in_row(&self, row: usize) -> Vec<(usize, usize)> {
+        let Some(search) = &self.search else {
+            return Vec::new();
+        };
+        let len = search.pattern.len().max(1);
+        search
+            .matches
+            .iter()
+            .filter(|&&(match_row, _)| match_row == row)
+            .map(|&(_, col)| (col, col + len))
+            .collect()
+    }
+
+    pub fn clear_search(&mut self) {
+        self.search = None;
+    }
+}
+
+/// Renders a [`CrateDetails`] record into plain, unwrapped text: name and
+/// version, full description, keywords, repository/homepage links, and the
+/// version history, one field per paragraph.
+fn render_detail_text(details: &CrateDetails) -> String {
+    let mut out = String::new();
+    out.push_str(&format!(
+        "{} {}\n\n",
+        details.name(),
+        details.newest_version()
+    ));
+    out.push_str(details.description());
+    out.push_str("\n\n");
+
+    if !details.keywords().is_empty() {
+        out.push_str("Keywords: ");
+        out.push_str(&details.keywords().join(", "));
+        out.push_str("\n\n");
+    }
+
+    if let Some(repository) = details.repository() {
+        out.push_str(&format!("Repository: {}\n", repository));
+    }
+    if let Some(homepage) = details.homepage() {
+        out.push_str(&format!("Homepage: {}\n", homepage));
+    }
+    out.push('\n');
+
+    out.push_str("Versions:\n");
+    for version in details.versions() {
+        out.push_str(&format!("  {}\n", version));
+    }
+
+    out
+}
+
+/// Word-wraps `text` to `width` columns, preserving existing blank lines.
+fn reflow(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        if paragraph.is_empty() {
+            lines.push(String::new());
+            continue;
+        }
+
+        let mut current = String::new();
+        for word in paragraph.split_whitespace() {
+            if current.is_empty() {
+                current.push_str(word);
+            } else if current.len() + 1 + word.len() <= width {
+                current.push(' ');
+                current.push_str(word);
+            } else {
+                lines.push(std::mem::take(&mut current));
+                current.push_str(word);
+            }
+        }
+        lines.push(current);
+    }
+    lines
+}
@@ -0,0 +1,81 @@
+//! In-memory glob/substring filtering of the currently displayed crates,
+//! borrowed from the glob-filter idiom in file-manager TUIs. Operates
+//! purely on a page already fetched from crates.io — no API round-trip.
+
+/// Returns true if `pattern` matches `text`. A pattern containing `*`/`?` is
+/// treated as a case-insensitive glob; otherwise it's a plain substring.
+pub fn matches(pattern: &str, text: &str) -> bool {
+    if pattern.is_empty() {
+        return true;
+    }
+
+    let text = text.to_ascii_lowercase();
+    let pattern = pattern.to_ascii_lowercase();
+    if pattern.contains('*') || pattern.contains('?') {
+        let pattern: Vec<char> = pattern.chars().collect();
+        let text: Vec<char> = text.chars().collect();
+        glob_match(&pattern, &text)
+    } else {
+        text.contains(&pattern)
+    }
+}
+
+fn glob_match(pattern: &[char], text: &[char]) -> bool {
+    match pattern.first() {
+        None => text.is_empty(),
+        Some('*') => {
+            glob_match(&pattern[1..], text) || (!text.is_empty() && glob_match(pattern, &text[1..]))
+        }
+        Some('?') if !text.is_empty() => glob_match(&pattern[1..], &text[1..]),
+        Some(c) if !text.is_empty() && text[0] == *c => glob_match(&pattern[1..], &text[1..]),
+        _ => false,
+    }
+}
+
+/// Returns true if `pattern` matches a crate's name or description.
+pub fn crate_matches(pattern: &str, name: &str, description: &str) -> bool {
+    matches(pattern, name) || matches(pattern, description)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_pattern_matches_anything() {
+        assert!(matches("", "anything"));
+    }
+
+    #[test]
+    fn plain_pattern_is_a_case_insensitive_substring_match() {
+        assert!(matches("Serde", "serde_json"));
+        assert!(!matches("xyz", "serde_json"));
+    }
+
+    #[test]
+    fn star_matches_any_run_of_characters() {
+        assert!(matches("serde*", "serde_json"));
+        assert!(matches("*json", "serde_json"));
+        assert!(matches("s*json", "serde_json"));
+        assert!(!matches("tokio*", "serde_json"));
+    }
+
+    #[test]
+    fn question_mark_matches_exactly_one_character() {
+        assert!(matches("serde_jso?", "serde_json"));
+        assert!(!matches("serde_jso??", "serde_json"));
+    }
+
+    #[test]
+    fn glob_pattern_must_match_the_whole_text() {
+        assert!(!matches("*xyz", "serde_json"));
+        assert!(matches("serde_json*", "serde_json"));
+    }
+
+    #[test]
+    fn crate_matches_checks_both_name_and_description() {
+        assert!(crate_matches("serde", "serde_json", "a JSON library"));
+        assert!(crate_matches("json", "serde", "a JSON library"));
+        assert!(!crate_matches("xml", "serde", "a JSON library"));
+    }
+}
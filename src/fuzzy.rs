@@ -0,0 +1,156 @@
+//! fzy/fozzie-style fuzzy matcher used to re-rank crates.io search results
+//! client-side once the server has already returned a page of candidates.
+
+/// Score returned for strings that are not a subsequence match of the query.
+pub const NEG_INFINITY: f64 = f64::NEG_INFINITY;
+/// Score returned for a candidate that matches the query exactly (case-insensitive).
+const EXACT_MATCH_SCORE: f64 = 1000.0;
+
+const SCORE_GAP_LEADING: f64 = -0.005;
+const SCORE_GAP_TRAILING: f64 = -0.005;
+const SCORE_GAP_INNER: f64 = -0.01;
+const SCORE_MATCH_CONSECUTIVE: f64 = 1.0;
+const SCORE_MATCH_SLASH: f64 = 0.9;
+const SCORE_MATCH_WORD: f64 = 0.8;
+const SCORE_MATCH_CAPITAL: f64 = 0.7;
+const SCORE_MATCH_DOT: f64 = 0.6;
+
+/// Returns true if every character of `query` appears in `candidate`, in order
+/// (case-insensitive).
+pub fn is_match(query: &str, candidate: &str) -> bool {
+    let mut candidate_chars = candidate.chars().map(|c| c.to_ascii_lowercase());
+    query
+        .chars()
+        .map(|c| c.to_ascii_lowercase())
+        .all(|qc| candidate_chars.any(|cc| cc == qc))
+}
+
+/// Bonus awarded for a query character matching at position `i` of `candidate`,
+/// based on what precedes it (start of string, after a separator, after a dot,
+/// or a capital following a lowercase letter).
+fn bonus_for(candidate: &[char], i: usize) -> f64 {
+    if i == 0 {
+        return SCORE_MATCH_WORD;
+    }
+
+    let prev = candidate[i - 1];
+    let cur = candidate[i];
+
+    match prev {
+        '/' => SCORE_MATCH_SLASH,
+        '-' | '_' | ' ' => SCORE_MATCH_WORD,
+        '.' => SCORE_MATCH_DOT,
+        _ if prev.is_lowercase() && cur.is_uppercase() => SCORE_MATCH_CAPITAL,
+        _ => 0.0,
+    }
+}
+
+/// Scores `candidate` against `query` using the fzy/fozzie dynamic-programming
+/// algorithm: `d[i][j]` is the best score for a match ending with query char
+/// `i` aligned to candidate char `j`, and `m[i][j]` is the best score matching
+/// the first `i` query chars within the first `j` candidate chars.
+///
+/// Returns [`NEG_INFINITY`] if `query` is not a subsequence of `candidate`, and
+/// [`EXACT_MATCH_SCORE`] if the two strings are equal (case-insensitive).
+pub fn score(query: &str, candidate: &str) -> f64 {
+    if query.is_empty() {
+        return 0.0;
+    }
+    if query.eq_ignore_ascii_case(candidate) {
+        return EXACT_MATCH_SCORE;
+    }
+    if !is_match(query, candidate) {
+        return NEG_INFINITY;
+    }
+
+    let query: Vec<char> = query.chars().map(|c| c.to_ascii_lowercase()).collect();
+    let candidate_lower: Vec<char> = candidate.chars().map(|c| c.to_ascii_lowercase()).collect();
+    let candidate_raw: Vec<char> = candidate.chars().collect();
+
+    let n = query.len();
+    let m = candidate_lower.len();
+
+    let mut d = vec![vec![NEG_INFINITY; m]; n];
+    let mut mat = vec![vec![NEG_INFINITY; m]; n];
+
+    for i in 0..n {
+        let mut prev_score = NEG_INFINITY;
+        let gap_score = if i == n - 1 {
+            SCORE_GAP_TRAILING
+        } else {
+            SCORE_GAP_INNER
+        };
+
+        for j in 0..m {
+            if query[i] == candidate_lower[j] {
+                let score = if i == 0 {
+                    (j as f64) * SCORE_GAP_LEADING + bonus_for(&candidate_raw, j)
+                } else if j == 0 {
+                    NEG_INFINITY
+                } else {
+                    let m_prev = mat[i - 1][j - 1];
+                    let consecutive = d[i - 1][j - 1] + SCORE_MATCH_CONSECUTIVE;
+                    f64::max(m_prev + bonus_for(&candidate_raw, j), consecutive)
+                };
+                d[i][j] = score;
+                prev_score = f64::max(score, prev_score + gap_score);
+                mat[i][j] = prev_score;
+            } else {
+                d[i][j] = NEG_INFINITY;
+                prev_score += gap_score;
+                mat[i][j] = prev_score;
+            }
+        }
+    }
+
+    mat[n - 1][m - 1]
+}
+
+/// Scores `candidate_a`/`candidate_b` (typically a crate's name and
+/// description) against `query`, returning the better of the two.
+pub fn best_score(query: &str, candidate_a: &str, candidate_b: &str) -> f64 {
+    f64::max(score(query, candidate_a), score(query, candidate_b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_match_gets_the_fixed_score() {
+        assert_eq!(score("serde", "serde"), EXACT_MATCH_SCORE);
+        assert_eq!(score("Serde", "serde"), EXACT_MATCH_SCORE);
+    }
+
+    #[test]
+    fn non_subsequence_is_neg_infinity() {
+        assert_eq!(score("xyz", "abc"), NEG_INFINITY);
+        assert!(!is_match("xyz", "abc"));
+    }
+
+    #[test]
+    fn word_boundary_match_outranks_mid_word_match() {
+        // The 'b' lands right after a `-` separator in the first candidate,
+        // but mid-word (after a lowercase letter, no case transition) in the
+        // second, so the first should score higher despite otherwise
+        // matching the same single-character query.
+        let boundary = score("b", "foo-bar");
+        let mid_word = score("b", "foobar");
+        assert!(boundary > mid_word, "{} should be > {}", boundary, mid_word);
+    }
+
+    #[test]
+    fn capitalized_match_outranks_plain_lowercase_match() {
+        // The query char aligns with a capital letter following a lowercase
+        // one in "FooBar" (earning SCORE_MATCH_CAPITAL), but with a plain lowercase
+        // interior letter in "foobar".
+        let capital = score("b", "FooBar");
+        let lowercase = score("b", "foobar");
+        assert!(capital > lowercase, "{} should be > {}", capital, lowercase);
+    }
+
+    #[test]
+    fn empty_query_scores_zero() {
+        assert_eq!(score("", "anything"), 0.0);
+    }
+}
@@ -1,12 +1,14 @@
 use std::{sync::mpsc::Sender, time::Duration};
 
-use crossterm::event::{self, Event as TermEvent, KeyCode};
+use crossterm::event::{self, Event as TermEvent, KeyEvent};
+
+use crate::crates_io::CrateDetails;
 
 pub enum InputEvent {
-    Char(char),
-    Esc,
-    Enter,
-    Backspace,
+    /// A raw key press, code and modifiers intact, resolved against the
+    /// active [`crate::keymap::Keymap`] by the app rather than here.
+    Key(KeyEvent),
+    Details(CrateDetails),
 }
 
 pub struct InputMonitor {
@@ -22,13 +24,7 @@ impl InputMonitor {
         loop {
             if let Ok(true) = event::poll(Duration::from_secs(10)) {
                 if let TermEvent::Key(key) = event::read().unwrap() {
-                    match key.code {
-                        KeyCode::Esc => self.tx.send(InputEvent::Esc).unwrap(),
-                        KeyCode::Enter => self.tx.send(InputEvent::Enter).unwrap(),
-                        KeyCode::Backspace => self.tx.send(InputEvent::Backspace).unwrap(),
-                        KeyCode::Char(c) => self.tx.send(InputEvent::Char(c)).unwrap(),
-                        _ => {}
-                    }
+                    self.tx.send(InputEvent::Key(key)).unwrap();
                 }
             }
         }
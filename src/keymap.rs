@@ -0,0 +1,225 @@
+//! Configurable keybindings: physical key combos (`ctrl-n`, `shift-j`, `q`,
+//! ...) mapped to logical [`Action`]s, loaded from
+//! `~/.config/cratuity/config.toml` and falling back to the built-in
+//! defaults for anything the file doesn't override.
+
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use serde::Deserialize;
+
+/// A logical action a keypress can trigger, independent of which physical
+/// key it's bound to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// Fetches the next page of search results in [`crate::app::AppMode::Normal`];
+    /// jumps to the next `/` search match in [`crate::app::AppMode::Detail`].
+    NextPage,
+    /// Fetches the previous page of search results in [`crate::app::AppMode::Normal`];
+    /// jumps to the previous `/` search match in [`crate::app::AppMode::Detail`].
+    PrevPage,
+    MoveUp,
+    MoveDown,
+    PageUp,
+    PageDown,
+    StartSearch,
+    /// The in-context `/` search: filters results in [`crate::app::AppMode::Normal`],
+    /// searches the pager's text in [`crate::app::AppMode::Detail`].
+    Search,
+    OpenSort,
+    Copy,
+    Quit,
+    /// Opens the highlighted crate's repository (falling back to its
+    /// homepage) in the default browser.
+    OpenRepository,
+    /// Opens the highlighted crate's docs.rs page in the default browser.
+    OpenDocs,
+    /// Toggles the highlighted crate's bookmark in [`crate::app::AppMode::Normal`].
+    ToggleBookmark,
+    /// Enters [`crate::app::AppMode::Bookmarks`] to browse saved crates.
+    OpenBookmarks,
+    /// Deletes the highlighted entry in [`crate::app::AppMode::Bookmarks`].
+    Delete,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct KeyCombo {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyCombo {
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    /// Parses combos like `n`, `ctrl-n`, or `shift-j`.
+    ///
+    /// Without the Kitty keyboard protocol (which this app doesn't enable),
+    /// terminals report a physically-shifted letter as the bare uppercase
+    /// char with no modifier bit set — e.g. `shift-j` arrives as `Char('J')`,
+    /// not `(Char('j'), SHIFT)`. So `shift-<letter>` is normalized the same
+    /// way here, rather than kept as a `SHIFT` modifier that would never
+    /// actually match an incoming key event.
+    fn parse(s: &str) -> Option<Self> {
+        let mut parts: Vec<&str> = s.split('-').collect();
+        let key = parts.pop()?;
+
+        let mut modifiers = KeyModifiers::NONE;
+        for part in parts {
+            modifiers |= match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return None,
+            };
+        }
+
+        let mut code = match key.to_ascii_lowercase().as_str() {
+            "esc" | "escape" => KeyCode::Esc,
+            "enter" | "return" => KeyCode::Enter,
+            "backspace" => KeyCode::Backspace,
+            "tab" => KeyCode::Tab,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            _ => {
+                let mut chars = key.chars();
+                let c = chars.next()?;
+                if chars.next().is_some() {
+                    return None;
+                }
+                KeyCode::Char(c)
+            }
+        };
+
+        if modifiers.contains(KeyModifiers::SHIFT) {
+            if let KeyCode::Char(c) = code {
+                modifiers.remove(KeyModifiers::SHIFT);
+                code = KeyCode::Char(c.to_ascii_uppercase());
+            }
+        }
+
+        Some(Self::new(code, modifiers))
+    }
+}
+
+/// Shape of `~/.config/cratuity/config.toml`: a `[bindings]` table of key
+/// combo to action name, e.g. `"ctrl-n" = "next_page"`. Keys outside that
+/// table are ignored.
+#[derive(Debug, Default, Deserialize)]
+struct KeymapFile {
+    #[serde(default)]
+    bindings: HashMap<String, String>,
+}
+
+pub struct Keymap {
+    bindings: HashMap<KeyCombo, Action>,
+}
+
+impl Keymap {
+    /// Loads the keymap from `~/.config/cratuity/config.toml`, falling back
+    /// to [`Keymap::default`] if the file is missing or malformed.
+    pub fn load() -> Self {
+        let mut keymap = Self::default();
+
+        let Some(contents) = config_path().and_then(|path| fs::read_to_string(path).ok()) else {
+            return keymap;
+        };
+        let Ok(file) = toml::from_str::<KeymapFile>(&contents) else {
+            return keymap;
+        };
+
+        for (combo, action) in file.bindings {
+            match (KeyCombo::parse(&combo), Action::parse(&action)) {
+                (Some(combo), Some(action)) => {
+                    keymap.bindings.insert(combo, action);
+                }
+                _ => continue,
+            }
+        }
+
+        keymap
+    }
+
+    /// Resolves a physical keypress to the action bound to it, if any.
+    pub fn resolve(&self, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        self.bindings.get(&KeyCombo::new(code, modifiers)).copied()
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let mut bindings = HashMap::new();
+        let mut bind = |c: char, action: Action| {
+            bindings.insert(KeyCombo::new(KeyCode::Char(c), KeyModifiers::NONE), action);
+        };
+
+        bind('f', Action::StartSearch);
+        bind('F', Action::StartSearch);
+        bind('q', Action::Quit);
+        bind('Q', Action::Quit);
+        bind('n', Action::NextPage);
+        bind('N', Action::NextPage);
+        bind('p', Action::PrevPage);
+        bind('P', Action::PrevPage);
+        bind('j', Action::MoveDown);
+        bind('J', Action::MoveDown);
+        bind('k', Action::MoveUp);
+        bind('K', Action::MoveUp);
+        bind('s', Action::OpenSort);
+        bind('S', Action::OpenSort);
+        bind('c', Action::Copy);
+        bind('C', Action::Copy);
+        bind('/', Action::Search);
+        bind('o', Action::OpenRepository);
+        bind('O', Action::OpenRepository);
+        bind('d', Action::OpenDocs);
+        bind('D', Action::OpenDocs);
+        bind('b', Action::ToggleBookmark);
+        bind('B', Action::ToggleBookmark);
+        bind('v', Action::OpenBookmarks);
+        bind('V', Action::OpenBookmarks);
+        bind('x', Action::Delete);
+        bind('X', Action::Delete);
+
+        drop(bind);
+        bindings.insert(
+            KeyCombo::new(KeyCode::PageUp, KeyModifiers::NONE),
+            Action::PageUp,
+        );
+        bindings.insert(
+            KeyCombo::new(KeyCode::PageDown, KeyModifiers::NONE),
+            Action::PageDown,
+        );
+
+        Self { bindings }
+    }
+}
+
+impl Action {
+    fn parse(s: &str) -> Option<Self> {
+        Some(match s {
+            "next_page" => Action::NextPage,
+            "prev_page" => Action::PrevPage,
+            "move_up" => Action::MoveUp,
+            "move_down" => Action::MoveDown,
+            "page_up" => Action::PageUp,
+            "page_down" => Action::PageDown,
+            "start_search" => Action::StartSearch,
+            "search" => Action::Search,
+            "open_sort" => Action::OpenSort,
+            "copy" => Action::Copy,
+            "quit" => Action::Quit,
+            "open_repository" => Action::OpenRepository,
+            "open_docs" => Action::OpenDocs,
+            "toggle_bookmark" => Action::ToggleBookmark,
+            "open_bookmarks" => Action::OpenBookmarks,
+            "delete" => Action::Delete,
+            _ => return None,
+        })
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("cratuity").join("config.toml"))
+}
@@ -1,30 +1,37 @@
 use std::{
     error::Error,
-    io,
+    fmt, io,
     io::Write,
+    str::FromStr,
     sync::mpsc::{self},
     thread,
 };
 
 use app::App;
 
-use crates_io::{CrateSearchResponse, CrateSearcher, CratesSort};
+use crates_io::{CrateSearch, CrateSearchResponse, CrateSearcher, CratesSort};
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
     execute,
-    terminal::{
-        disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen, ScrollUp,
-    },
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use input::InputMonitor;
 
+use serde::Serialize;
 use structopt::StructOpt;
 
-use tui::{backend::CrosstermBackend, layout::Rect, widgets::Paragraph, Terminal};
+use tui::{backend::CrosstermBackend, Terminal};
 
 mod app;
+mod bookmarks;
 mod crates_io;
+mod detail;
+mod filter;
+mod fuzzy;
 mod input;
+mod keymap;
+mod textarea;
+mod url;
 mod widgets;
 
 pub(crate) fn ceil_div(a: u32, b: u32) -> u32 {
@@ -52,6 +59,53 @@ pub struct AppArgs {
 
     #[structopt(short, long, default_value = "5")]
     pub count: usize,
+
+    /// Output format used by `--find`: `table` for humans, `json`/`tsv` for
+    /// piping into scripts or `jq`.
+    #[structopt(
+        long,
+        default_value,
+        possible_values = &["table", "json", "tsv"],
+        case_insensitive = true
+    )]
+    pub format: OutputFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Tsv,
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self {
+        OutputFormat::Table
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            OutputFormat::Table => "table",
+            OutputFormat::Json => "json",
+            OutputFormat::Tsv => "tsv",
+        };
+        f.write_str(s)
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "table" => Ok(OutputFormat::Table),
+            "json" => Ok(OutputFormat::Json),
+            "tsv" => Ok(OutputFormat::Tsv),
+            _ => Err(format!("unknown format `{}`", s)),
+        }
+    }
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -63,7 +117,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     let args: AppArgs = AppArgs::from_clap(&matches);
     if let Some(find) = args.find {
-        cli_search(find.as_str(), args.sort, args.count)?;
+        cli_search(find.as_str(), args.sort, args.count, args.format)?;
 
         return Ok(());
     }
@@ -96,24 +150,111 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-fn cli_search(term: &str, sort: CratesSort, count: usize) -> Result<(), Box<dyn Error>> {
+fn cli_search(
+    term: &str,
+    sort: CratesSort,
+    count: usize,
+    format: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
     let crate_search = CrateSearcher::new()?;
     let resp = crate_search.search_sorted_count(term, 1, count as u32, &sort)?;
-    print_crates_table(resp)
+    match format {
+        OutputFormat::Table => print_crates_table(resp),
+        OutputFormat::Json => print_crates_json(resp),
+        OutputFormat::Tsv => print_crates_tsv(resp),
+    }
+}
+
+/// A crate's fields as serialized for the `json` output format.
+#[derive(Serialize)]
+struct CrateRow<'a> {
+    name: &'a str,
+    version: &'a str,
+    downloads: u64,
+    description: &'a str,
+}
+
+impl<'a> CrateRow<'a> {
+    fn new(crte: &'a CrateSearch) -> Self {
+        Self {
+            name: crte.name(),
+            version: crte.version(),
+            downloads: crte.downloads(),
+            description: crte.description(),
+        }
+    }
 }
 
+/// Prints `crates` as an aligned, human-readable text table to stdout.
 fn print_crates_table(crates: CrateSearchResponse) -> Result<(), Box<dyn Error>> {
-    // Print a table with TUI
+    const HEADERS: [&str; 4] = ["Name", "Version", "Downloads", "Description"];
+
+    let rows: Vec<[String; 4]> = crates
+        .crates
+        .iter()
+        .map(|crte| {
+            [
+                crte.name().to_string(),
+                crte.version().to_string(),
+                crte.downloads().to_string(),
+                crte.description().to_string(),
+            ]
+        })
+        .collect();
+
+    let mut widths = HEADERS.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
     let mut stdout = io::stdout();
-    execute!(stdout, ScrollUp(10))?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-    let (_, cursor_y) = terminal.get_cursor()?;
-    terminal.set_cursor(0, cursor_y - 10)?;
+    print_row(&mut stdout, &HEADERS.map(str::to_string), &widths)?;
+    for row in &rows {
+        print_row(&mut stdout, row, &widths)?;
+    }
+
+    Ok(())
+}
+
+fn print_row(
+    stdout: &mut io::Stdout,
+    cells: &[String; 4],
+    widths: &[usize; 4],
+) -> io::Result<()> {
+    for (i, (cell, width)) in cells.iter().zip(widths).enumerate() {
+        if i > 0 {
+            write!(stdout, "  ")?;
+        }
+        write!(stdout, "{:width$}", cell, width = width)?;
+    }
+    writeln!(stdout)
+}
 
-    let (_, cursor_y) = terminal.get_cursor()?;
-    let window = terminal.get_frame().size();
-    let area = Rect::new(0, cursor_y, window.width, window.height - cursor_y);
+/// Prints `crates` as a JSON array to stdout, bypassing the TUI entirely.
+fn print_crates_json(crates: CrateSearchResponse) -> Result<(), Box<dyn Error>> {
+    let rows: Vec<CrateRow> = crates.crates.iter().map(CrateRow::new).collect();
+    println!("{}", serde_json::to_string_pretty(&rows)?);
+    Ok(())
+}
+
+/// Prints `crates` as tab-separated values to stdout, bypassing the TUI
+/// entirely. Tabs/newlines within fields are replaced with spaces so each
+/// crate stays on its own line.
+fn print_crates_tsv(crates: CrateSearchResponse) -> Result<(), Box<dyn Error>> {
+    let sanitize = |s: &str| s.replace(['\t', '\n'], " ");
+
+    println!("name\tversion\tdownloads\tdescription");
+    for crte in &crates.crates {
+        println!(
+            "{}\t{}\t{}\t{}",
+            sanitize(crte.name()),
+            sanitize(crte.version()),
+            crte.downloads(),
+            sanitize(crte.description()),
+        );
+    }
 
     Ok(())
 }
@@ -0,0 +1,283 @@
+//! A single-line editable text field backing the search box: cursor
+//! movement, word/line deletion, and an undo/redo history of edits.
+
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// A buffer/cursor pair captured before a mutating edit, so it can be
+/// restored by [`TextArea::undo`]/[`TextArea::redo`].
+#[derive(Clone)]
+struct Snapshot {
+    chars: Vec<char>,
+    cursor: usize,
+}
+
+pub struct TextArea {
+    chars: Vec<char>,
+    cursor: usize,
+    undo_stack: Vec<Snapshot>,
+    redo_stack: Vec<Snapshot>,
+}
+
+impl TextArea {
+    pub fn new() -> Self {
+        Self {
+            chars: Vec::new(),
+            cursor: 0,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+        }
+    }
+
+    pub fn as_string(&self) -> String {
+        self.chars.iter().collect()
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty()
+    }
+
+    /// Clears the field back to empty, discarding history, and returns what
+    /// it held — used when a search is confirmed with Enter.
+    pub fn take(&mut self) -> String {
+        let text = self.as_string();
+        self.chars.clear();
+        self.cursor = 0;
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        text
+    }
+
+    /// Routes a key event into the field. Returns `true` if it mutated or
+    /// moved the cursor.
+    pub fn handle_key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        match (code, modifiers) {
+            (KeyCode::Left, _) => self.move_left(),
+            (KeyCode::Right, _) => self.move_right(),
+            (KeyCode::Home, _) => self.move_home(),
+            (KeyCode::End, _) => self.move_end(),
+            (KeyCode::Char('w'), KeyModifiers::CONTROL) => self.delete_word_back(),
+            (KeyCode::Char('u'), KeyModifiers::CONTROL) => self.kill_to_start(),
+            (KeyCode::Char('z'), KeyModifiers::CONTROL) => self.undo(),
+            (KeyCode::Char('y'), KeyModifiers::CONTROL) => self.redo(),
+            (KeyCode::Backspace, _) => self.delete_back(),
+            (KeyCode::Char(c), _) => self.insert(c),
+            _ => false,
+        }
+    }
+
+    fn push_undo(&mut self) {
+        self.undo_stack.push(Snapshot {
+            chars: self.chars.clone(),
+            cursor: self.cursor,
+        });
+        self.redo_stack.clear();
+    }
+
+    pub fn insert(&mut self, c: char) -> bool {
+        self.push_undo();
+        self.chars.insert(self.cursor, c);
+        self.cursor += 1;
+        true
+    }
+
+    pub fn delete_back(&mut self) -> bool {
+        if self.cursor == 0 {
+            return false;
+        }
+        self.push_undo();
+        self.cursor -= 1;
+        self.chars.remove(self.cursor);
+        true
+    }
+
+    /// Ctrl-W: deletes the run of whitespace then the word before the
+    /// cursor.
+    pub fn delete_word_back(&mut self) -> bool {
+        if self.cursor == 0 {
+            return false;
+        }
+        self.push_undo();
+        let mut start = self.cursor;
+        while start > 0 && self.chars[start - 1].is_whitespace() {
+            start -= 1;
+        }
+        while start > 0 && !self.chars[start - 1].is_whitespace() {
+            start -= 1;
+        }
+        self.chars.drain(start..self.cursor);
+        self.cursor = start;
+        true
+    }
+
+    /// Ctrl-U: kills from the line start up to the cursor.
+    pub fn kill_to_start(&mut self) -> bool {
+        if self.cursor == 0 {
+            return false;
+        }
+        self.push_undo();
+        self.chars.drain(0..self.cursor);
+        self.cursor = 0;
+        true
+    }
+
+    pub fn move_left(&mut self) -> bool {
+        if self.cursor == 0 {
+            return false;
+        }
+        self.cursor -= 1;
+        true
+    }
+
+    pub fn move_right(&mut self) -> bool {
+        if self.cursor >= self.chars.len() {
+            return false;
+        }
+        self.cursor += 1;
+        true
+    }
+
+    pub fn move_home(&mut self) -> bool {
+        let moved = self.cursor != 0;
+        self.cursor = 0;
+        moved
+    }
+
+    pub fn move_end(&mut self) -> bool {
+        let moved = self.cursor != self.chars.len();
+        self.cursor = self.chars.len();
+        moved
+    }
+
+    pub fn undo(&mut self) -> bool {
+        if let Some(snapshot) = self.undo_stack.pop() {
+            self.redo_stack.push(Snapshot {
+                chars: self.chars.clone(),
+                cursor: self.cursor,
+            });
+            self.chars = snapshot.chars;
+            self.cursor = snapshot.cursor;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn redo(&mut self) -> bool {
+        if let Some(snapshot) = self.redo_stack.pop() {
+            self.undo_stack.push(Snapshot {
+                chars: self.chars.clone(),
+                cursor: self.cursor,
+            });
+            self.chars = snapshot.chars;
+            self.cursor = snapshot.cursor;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Default for TextArea {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(s: &str) -> TextArea {
+        let mut area = TextArea::new();
+        for c in s.chars() {
+            area.insert(c);
+        }
+        area
+    }
+
+    #[test]
+    fn move_left_at_start_is_a_no_op() {
+        let mut area = typed("hi");
+        area.move_home();
+        assert!(!area.move_left());
+        assert_eq!(area.cursor(), 0);
+    }
+
+    #[test]
+    fn move_right_at_end_is_a_no_op() {
+        let mut area = typed("hi");
+        assert!(!area.move_right());
+        assert_eq!(area.cursor(), 2);
+    }
+
+    #[test]
+    fn delete_back_at_start_is_a_no_op() {
+        let mut area = TextArea::new();
+        assert!(!area.delete_back());
+    }
+
+    #[test]
+    fn insert_moves_cursor_past_the_inserted_char() {
+        let area = typed("ab");
+        assert_eq!(area.as_string(), "ab");
+        assert_eq!(area.cursor(), 2);
+    }
+
+    #[test]
+    fn delete_word_back_stops_at_the_previous_word_boundary() {
+        let mut area = typed("foo bar");
+        area.delete_word_back();
+        assert_eq!(area.as_string(), "foo ");
+        assert_eq!(area.cursor(), 4);
+    }
+
+    #[test]
+    fn kill_to_start_removes_everything_before_the_cursor() {
+        let mut area = typed("foo bar");
+        area.move_left();
+        area.move_left();
+        area.kill_to_start();
+        assert_eq!(area.as_string(), "ar");
+        assert_eq!(area.cursor(), 0);
+    }
+
+    #[test]
+    fn undo_restores_the_pre_edit_buffer_and_cursor() {
+        let mut area = typed("ab");
+        area.delete_back();
+        assert_eq!(area.as_string(), "a");
+        assert!(area.undo());
+        assert_eq!(area.as_string(), "ab");
+        assert_eq!(area.cursor(), 2);
+    }
+
+    #[test]
+    fn redo_reapplies_an_undone_edit() {
+        let mut area = typed("ab");
+        area.delete_back();
+        area.undo();
+        assert!(area.redo());
+        assert_eq!(area.as_string(), "a");
+    }
+
+    #[test]
+    fn undo_with_empty_history_is_a_no_op() {
+        let mut area = TextArea::new();
+        assert!(!area.undo());
+    }
+
+    #[test]
+    fn take_clears_the_buffer_and_history() {
+        let mut area = typed("ab");
+        area.delete_back();
+        let taken = area.take();
+        assert_eq!(taken, "a");
+        assert!(area.is_empty());
+        assert_eq!(area.cursor(), 0);
+        assert!(!area.undo());
+    }
+}
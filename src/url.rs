@@ -0,0 +1,138 @@
+//! Detecting `http(s)://` URLs embedded in crate text fields, and opening
+//! them in the user's default browser.
+
+use std::io;
+
+/// A detected URL's byte span within the string it was found in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UrlSpan {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl UrlSpan {
+    pub fn as_str<'a>(&self, text: &'a str) -> &'a str {
+        &text[self.start..self.end]
+    }
+}
+
+/// Scans `text` for `http://`/`https://` spans, stopping at whitespace and
+/// trimming trailing punctuation (`)`, `.`, `,`) that's almost certainly not
+/// part of the URL.
+pub fn locate_urls(text: &str) -> Vec<UrlSpan> {
+    let mut spans = Vec::new();
+    let mut search_from = 0;
+
+    while let Some(offset) = text[search_from..].find("http") {
+        let start = search_from + offset;
+        let rest = &text[start..];
+        if !(rest.starts_with("http://") || rest.starts_with("https://")) {
+            search_from = start + 4;
+            continue;
+        }
+
+        let mut end = start;
+        for (offset, c) in rest.char_indices() {
+            if c.is_whitespace() {
+                break;
+            }
+            end = start + offset + c.len_utf8();
+        }
+        while end > start {
+            match text[start..end].chars().last() {
+                Some(')') | Some('.') | Some(',') => end -= 1,
+                _ => break,
+            }
+        }
+
+        if end > start {
+            spans.push(UrlSpan { start, end });
+        }
+        search_from = end.max(start + 4);
+    }
+
+    spans
+}
+
+/// Opens `url` with the OS-appropriate handler (`xdg-open` on Linux, `open`
+/// on macOS, `start` on Windows).
+#[cfg(not(feature = "no-open"))]
+pub fn open_url(url: &str) -> io::Result<()> {
+    #[cfg(target_os = "macos")]
+    let mut command = std::process::Command::new("open");
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut command = std::process::Command::new("cmd");
+        command.args(["/C", "start"]);
+        command
+    };
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let mut command = std::process::Command::new("xdg-open");
+
+    command.arg(url).spawn()?;
+    Ok(())
+}
+
+/// No-op when the `no-open` feature disables shelling out to a browser.
+#[cfg(feature = "no-open")]
+pub fn open_url(_url: &str) -> io::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn found(text: &str) -> Vec<&str> {
+        locate_urls(text)
+            .iter()
+            .map(|span| span.as_str(text))
+            .collect()
+    }
+
+    #[test]
+    fn finds_a_bare_url() {
+        assert_eq!(
+            found("see https://example.com/crate for more"),
+            vec!["https://example.com/crate"]
+        );
+    }
+
+    #[test]
+    fn finds_multiple_urls() {
+        assert_eq!(
+            found("http://a.example and https://b.example too"),
+            vec!["http://a.example", "https://b.example"]
+        );
+    }
+
+    #[test]
+    fn trims_trailing_punctuation() {
+        assert_eq!(
+            found("repo: https://example.com/crate)."),
+            vec!["https://example.com/crate"]
+        );
+        assert_eq!(
+            found("see https://example.com/crate,"),
+            vec!["https://example.com/crate"]
+        );
+    }
+
+    #[test]
+    fn stops_at_whitespace() {
+        assert_eq!(
+            found("https://example.com/crate\nnext line"),
+            vec!["https://example.com/crate"]
+        );
+    }
+
+    #[test]
+    fn ignores_http_without_a_scheme_separator() {
+        assert!(found("httpster is not a url").is_empty());
+    }
+
+    #[test]
+    fn no_urls_in_plain_text() {
+        assert!(found("just a regular crate description").is_empty());
+    }
+}